@@ -1,7 +1,12 @@
+use std::iter::FromIterator;
+use std::ptr;
+
 type Link<T> = Option<Box<Node<T>>>; // Box<> is like unique_ptr<>
 
 pub struct List<T> {
     head: Link<T>, // Optional pointer on the stack, actual nodes on the heap
+    tail: *mut Node<T>, // Raw pointer to the last node, for O(1) push_back
+    len: usize,
 }
 
 struct Node<T> {
@@ -11,19 +16,49 @@ struct Node<T> {
 
 impl<T> List<T> {
     pub fn new() -> Self {
-        List { head: None }
+        List { head: None, tail: ptr::null_mut(), len: 0 }
     }
 
     pub fn push(&mut self, elem: T) {
-        self.head = Some(Box::new(Node {
+        let was_empty = self.head.is_none();
+        let mut new_head = Box::new(Node {
             elem,
             next: self.head.take(),
-        }));
+        });
+        if was_empty {
+            self.tail = &mut *new_head;
+        }
+        self.head = Some(new_head);
+        self.len += 1;
+    }
+
+    // Appends at the tail in O(1) by keeping a raw pointer to the last node
+    pub fn push_back(&mut self, elem: T) {
+        let mut new_tail = Box::new(Node {
+            elem,
+            next: None,
+        });
+        let raw_tail: *mut _ = &mut *new_tail;
+
+        if !self.tail.is_null() {
+            unsafe {
+                (*self.tail).next = Some(new_tail);
+            }
+        } else {
+            self.head = Some(new_tail);
+        }
+
+        self.tail = raw_tail;
+        self.len += 1;
     }
 
     pub fn pop(&mut self) -> Option<T> {
         self.head.take().map(|node| {
             self.head = node.next;
+            if self.head.is_none() {
+                self.tail = ptr::null_mut();
+            }
+            self.len -= 1;
             node.elem
         })
     }
@@ -39,6 +74,14 @@ impl<T> List<T> {
             &mut node.elem
         })
     }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
 }
 
 // Avoid recursion in default drop
@@ -54,6 +97,30 @@ impl<T> Drop for List<T> {
     }
 }
 
+impl<T> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = List::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> Extend<T> for List<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter {
+            self.push_back(elem);
+        }
+    }
+}
+
+// Rebuilds the chain iteratively via push_back, to stay consistent with the
+// non-recursive Drop above.
+impl<T: Clone> Clone for List<T> {
+    fn clone(&self) -> Self {
+        self.into_iter().cloned().collect()
+    }
+}
+
 pub struct ListIntoIter<T>(List<T>);
 
 impl<T> IntoIterator for List<T> {
@@ -145,6 +212,73 @@ mod test {
         assert_eq!(None, l.pop());
     }
 
+    #[test]
+    fn push_back_and_pop() {
+        let mut l = List::new();
+        assert_eq!(None, l.pop());
+
+        l.push_back(1);
+        l.push_back(2);
+        l.push_back(3);
+        assert_eq!(Some(1), l.pop());
+        assert_eq!(Some(2), l.pop());
+
+        l.push_back(4);
+        assert_eq!(Some(3), l.pop());
+        assert_eq!(Some(4), l.pop());
+        assert_eq!(None, l.pop());
+
+        // tail must be reset so a fresh push_back after draining works
+        l.push_back(5);
+        assert_eq!(Some(5), l.pop());
+    }
+
+    #[test]
+    fn len_and_is_empty() {
+        let mut l = List::new();
+        assert_eq!(0, l.len());
+        assert!(l.is_empty());
+
+        l.push(1);
+        l.push_back(2);
+        assert_eq!(2, l.len());
+        assert!(!l.is_empty());
+
+        l.pop();
+        l.pop();
+        assert_eq!(0, l.len());
+        assert!(l.is_empty());
+    }
+
+    #[test]
+    fn from_iter_and_extend() {
+        let mut l: List<_> = (1..=3).collect();
+        assert_eq!(3, l.len());
+        assert_eq!(Some(1), l.pop());
+        assert_eq!(Some(2), l.pop());
+        assert_eq!(Some(3), l.pop());
+        assert_eq!(None, l.pop());
+
+        l.extend(4..=6);
+        assert_eq!(Some(4), l.pop());
+        assert_eq!(Some(5), l.pop());
+        assert_eq!(Some(6), l.pop());
+    }
+
+    #[test]
+    fn clone() {
+        let l: List<_> = (1..=3).collect();
+        let mut cloned = l.clone();
+
+        assert_eq!(l.len(), cloned.len());
+        assert_eq!(Some(1), cloned.pop());
+        assert_eq!(Some(2), cloned.pop());
+        assert_eq!(Some(3), cloned.pop());
+
+        // Original is untouched
+        assert_eq!(3, l.len());
+    }
+
     #[test]
     fn peek() {
         let mut l = List::new();
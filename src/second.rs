@@ -0,0 +1,119 @@
+use std::rc::Rc;
+
+type Link<T> = Option<Rc<Node<T>>>;
+
+pub struct SharedList<T> {
+    head: Link<T>,
+}
+
+struct Node<T> {
+    elem: T,
+    next: Link<T>,
+}
+
+impl<T> SharedList<T> {
+    pub fn new() -> Self {
+        SharedList { head: None }
+    }
+
+    pub fn prepend(&self, elem: T) -> SharedList<T> {
+        SharedList {
+            head: Some(Rc::new(Node {
+                elem,
+                next: self.head.clone(),
+            })),
+        }
+    }
+
+    pub fn tail(&self) -> SharedList<T> {
+        SharedList {
+            head: self.head.as_ref().and_then(|node| node.next.clone()),
+        }
+    }
+
+    pub fn head(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.elem)
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { next: self.head.as_deref() }
+    }
+}
+
+// Avoid recursion in default drop, but stop as soon as another list still
+// shares the rest of the chain, so we don't free nodes out from under it.
+impl<T> Drop for SharedList<T> {
+    fn drop(&mut self) {
+        let mut head = self.head.take();
+        while let Some(node) = head {
+            match Rc::try_unwrap(node) {
+                Ok(mut node) => head = node.next.take(),
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.elem
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SharedList;
+
+    #[test]
+    fn basics() {
+        let list = SharedList::new();
+        assert_eq!(list.head(), None);
+
+        let list = list.prepend(1).prepend(2).prepend(3);
+        assert_eq!(list.head(), Some(&3));
+
+        let list = list.tail();
+        assert_eq!(list.head(), Some(&2));
+
+        let list = list.tail();
+        assert_eq!(list.head(), Some(&1));
+
+        let list = list.tail();
+        assert_eq!(list.head(), None);
+
+        // Tail of an empty list is still empty
+        assert_eq!(list.tail().head(), None);
+    }
+
+    #[test]
+    fn iter() {
+        let list = SharedList::new().prepend(1).prepend(2).prepend(3);
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn branching_is_shared() {
+        let list = SharedList::new().prepend(1);
+        let branch_a = list.prepend(2);
+        let branch_b = list.prepend(3);
+
+        assert_eq!(branch_a.head(), Some(&2));
+        assert_eq!(branch_b.head(), Some(&3));
+        assert_eq!(branch_a.tail().head(), Some(&1));
+        assert_eq!(branch_b.tail().head(), Some(&1));
+    }
+}
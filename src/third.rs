@@ -0,0 +1,185 @@
+use std::cell::{Ref, RefCell, RefMut};
+use std::rc::Rc;
+
+type Link<T> = Option<Rc<RefCell<Node<T>>>>;
+
+struct Node<T> {
+    elem: T,
+    next: Link<T>,
+    prev: Link<T>,
+}
+
+pub struct Deque<T> {
+    head: Link<T>,
+    tail: Link<T>,
+}
+
+impl<T> Node<T> {
+    fn new(elem: T) -> Rc<RefCell<Self>> {
+        Rc::new(RefCell::new(Node {
+            elem,
+            next: None,
+            prev: None,
+        }))
+    }
+}
+
+impl<T> Deque<T> {
+    pub fn new() -> Self {
+        Deque { head: None, tail: None }
+    }
+
+    pub fn push_front(&mut self, elem: T) {
+        let new_head = Node::new(elem);
+        match self.head.take() {
+            Some(old_head) => {
+                old_head.borrow_mut().prev = Some(new_head.clone());
+                new_head.borrow_mut().next = Some(old_head);
+                self.head = Some(new_head);
+            }
+            None => {
+                self.tail = Some(new_head.clone());
+                self.head = Some(new_head);
+            }
+        }
+    }
+
+    pub fn push_back(&mut self, elem: T) {
+        let new_tail = Node::new(elem);
+        match self.tail.take() {
+            Some(old_tail) => {
+                old_tail.borrow_mut().next = Some(new_tail.clone());
+                new_tail.borrow_mut().prev = Some(old_tail);
+                self.tail = Some(new_tail);
+            }
+            None => {
+                self.head = Some(new_tail.clone());
+                self.tail = Some(new_tail);
+            }
+        }
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.take().map(|old_head| {
+            match old_head.borrow_mut().next.take() {
+                Some(new_head) => {
+                    new_head.borrow_mut().prev.take();
+                    self.head = Some(new_head);
+                }
+                None => {
+                    self.tail.take();
+                }
+            }
+            Rc::try_unwrap(old_head).ok().unwrap().into_inner().elem
+        })
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.tail.take().map(|old_tail| {
+            match old_tail.borrow_mut().prev.take() {
+                Some(new_tail) => {
+                    new_tail.borrow_mut().next.take();
+                    self.tail = Some(new_tail);
+                }
+                None => {
+                    self.head.take();
+                }
+            }
+            Rc::try_unwrap(old_tail).ok().unwrap().into_inner().elem
+        })
+    }
+
+    pub fn peek_front(&self) -> Option<Ref<'_, T>> {
+        self.head.as_ref().map(|node| Ref::map(node.borrow(), |node| &node.elem))
+    }
+
+    pub fn peek_back(&self) -> Option<Ref<'_, T>> {
+        self.tail.as_ref().map(|node| Ref::map(node.borrow(), |node| &node.elem))
+    }
+
+    pub fn peek_front_mut(&mut self) -> Option<RefMut<'_, T>> {
+        self.head.as_ref().map(|node| RefMut::map(node.borrow_mut(), |node| &mut node.elem))
+    }
+
+    pub fn peek_back_mut(&mut self) -> Option<RefMut<'_, T>> {
+        self.tail.as_ref().map(|node| RefMut::map(node.borrow_mut(), |node| &mut node.elem))
+    }
+}
+
+// next/prev form a reference cycle, so the default drop would leak; pop from
+// the front until empty to break every cycle explicitly.
+impl<T> Drop for Deque<T> {
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Deque;
+
+    #[test]
+    fn push_pop_front() {
+        let mut d = Deque::new();
+        assert_eq!(None, d.pop_front());
+
+        d.push_front(1);
+        d.push_front(2);
+        d.push_front(3);
+        assert_eq!(Some(3), d.pop_front());
+        assert_eq!(Some(2), d.pop_front());
+
+        d.push_front(4);
+        assert_eq!(Some(4), d.pop_front());
+        assert_eq!(Some(1), d.pop_front());
+        assert_eq!(None, d.pop_front());
+    }
+
+    #[test]
+    fn push_pop_back() {
+        let mut d = Deque::new();
+        assert_eq!(None, d.pop_back());
+
+        d.push_back(1);
+        d.push_back(2);
+        d.push_back(3);
+        assert_eq!(Some(3), d.pop_back());
+        assert_eq!(Some(2), d.pop_back());
+
+        d.push_back(4);
+        assert_eq!(Some(4), d.pop_back());
+        assert_eq!(Some(1), d.pop_back());
+        assert_eq!(None, d.pop_back());
+    }
+
+    #[test]
+    fn mixed_ends() {
+        let mut d = Deque::new();
+        d.push_front(1);
+        d.push_back(2);
+        d.push_front(0);
+        d.push_back(3);
+        // [0, 1, 2, 3]
+        assert_eq!(Some(0), d.pop_front());
+        assert_eq!(Some(3), d.pop_back());
+        assert_eq!(Some(1), d.pop_front());
+        assert_eq!(Some(2), d.pop_back());
+        assert_eq!(None, d.pop_front());
+        assert_eq!(None, d.pop_back());
+    }
+
+    #[test]
+    fn peek() {
+        let mut d = Deque::new();
+        assert!(d.peek_front().is_none());
+        assert!(d.peek_back().is_none());
+
+        d.push_front(1);
+        d.push_back(2);
+        assert_eq!(&1, &*d.peek_front().unwrap());
+        assert_eq!(&2, &*d.peek_back().unwrap());
+
+        *d.peek_front_mut().unwrap() = 42;
+        assert_eq!(&42, &*d.peek_front().unwrap());
+    }
+}